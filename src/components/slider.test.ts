@@ -0,0 +1,74 @@
+import { expect } from "https://deno.land/std@0.208.0/expect/mod.ts";
+import { describe, it } from "https://deno.land/std@0.208.0/testing/bdd.ts";
+import { Slider } from "./slider.tsx";
+
+// The tests call the event handlers directly on the returned element rather
+// than rendering into a DOM, so these fake just enough of the events.
+
+function pointerEvent(clientX: number): any {
+    return {
+        clientX,
+        pointerId: 1,
+        currentTarget: {
+            getBoundingClientRect: () => ({ left: 100, width: 200 }),
+            setPointerCapture: () => {},
+            hasPointerCapture: () => true,
+        },
+    };
+}
+
+function keyEvent(key: string): any {
+    return {
+        key,
+        ctrlKey: false,
+        altKey: false,
+        metaKey: false,
+        shiftKey: false,
+        preventDefault: () => {},
+        stopPropagation: () => {},
+    };
+}
+
+function changes(props: { value: number; min?: number; max?: number; step?: number }) {
+    const values: number[] = [];
+    const el = Slider({ ...props, onChange: (v) => values.push(v) });
+    return { props: el.props, values };
+}
+
+describe("Slider", () => {
+    it("should produce min and max when dragged to the ends of the track", () => {
+        const { props, values } = changes({ value: 5, min: 0, max: 10 });
+        props.onPointerDown(pointerEvent(100));
+        props.onPointerMove(pointerEvent(300));
+        expect(values).toEqual([0, 10]);
+    });
+
+    it("should clamp values dragged past the track to the range", () => {
+        const { props, values } = changes({ value: 5, min: 0, max: 10 });
+        props.onPointerDown(pointerEvent(-50));
+        props.onPointerMove(pointerEvent(1000));
+        expect(values).toEqual([0, 10]);
+    });
+
+    it("should snap values to the step", () => {
+        const { props, values } = changes({ value: 0, min: 0, max: 100, step: 25 });
+        props.onPointerDown(pointerEvent(170));
+        expect(values).toEqual([25]);
+    });
+
+    it("should adjust the value with the keyboard within the range", () => {
+        const { props, values } = changes({ value: 9, min: 0, max: 10 });
+        props.onKeyDown(keyEvent("ArrowRight"));
+        props.onKeyDown(keyEvent("ArrowLeft"));
+        props.onKeyDown(keyEvent("PageUp"));
+        props.onKeyDown(keyEvent("Home"));
+        expect(values).toEqual([10, 8, 10, 0]);
+    });
+
+    it("should not emit a change when the value is already at the limit", () => {
+        const { props, values } = changes({ value: 10, min: 0, max: 10 });
+        props.onKeyDown(keyEvent("ArrowRight"));
+        props.onKeyDown(keyEvent("End"));
+        expect(values).toEqual([]);
+    });
+});
@@ -0,0 +1,108 @@
+import React, { JSX } from "react";
+import { Element, TagProps } from "./element.tsx";
+import { Div } from "./div.tsx";
+import { handleKeyMapping } from "../util/key_mapping.ts";
+
+type SliderProps =
+    & Omit<TagProps<"div">, "tag" | "onChange">
+    & {
+        value: number;
+        min?: number;
+        max?: number;
+        step?: number;
+        onChange?: (value: number) => void;
+    };
+
+/**
+ * Controlled horizontal slider for picking a numeric value in [min, max].
+ *
+ * - Click or drag on the track to set the value
+ * - Arrow keys adjust by `step`, PageUp/PageDown by 10 steps, Home/End jump
+ *   to the ends of the range
+ *
+ * Values are always snapped to `step` and clamped to the range before
+ * `onChange` is called, and `onChange` is only called if the value changes.
+ */
+export function Slider(
+    {
+        value,
+        min = 0,
+        max = 100,
+        step = 1,
+        onChange,
+        onKeyDown,
+        onPointerDown,
+        onPointerMove,
+        ...rest
+    }: SliderProps,
+): JSX.Element {
+    const current = clampValue(value, min, max, step);
+    const percent = max > min ? ((current - min) / (max - min)) * 100 : 0;
+
+    const update = (next: number) => {
+        next = clampValue(next, min, max, step);
+        if (next !== current) {
+            onChange?.(next);
+        }
+    };
+    const updateFromPointer = (evt: React.PointerEvent<HTMLDivElement>) => {
+        const rect = evt.currentTarget.getBoundingClientRect();
+        const fraction = rect.width > 0 ? (evt.clientX - rect.left) / rect.width : 0;
+        update(min + fraction * (max - min));
+    };
+
+    const handlePointerDown = (evt: React.PointerEvent<HTMLDivElement>) => {
+        // Capture the pointer so dragging continues to update the value even
+        // when the pointer leaves the slider.
+        evt.currentTarget.setPointerCapture(evt.pointerId);
+        updateFromPointer(evt);
+        onPointerDown?.(evt);
+    };
+    const handlePointerMove = (evt: React.PointerEvent<HTMLDivElement>) => {
+        if (evt.currentTarget.hasPointerCapture(evt.pointerId)) {
+            updateFromPointer(evt);
+        }
+        onPointerMove?.(evt);
+    };
+    const handleKeyDown = (evt: React.KeyboardEvent<HTMLDivElement>) => {
+        handleKeyMapping(evt, {
+            "ArrowLeft,ArrowDown": () => update(current - step),
+            "ArrowRight,ArrowUp": () => update(current + step),
+            "PageDown": () => update(current - step * 10),
+            "PageUp": () => update(current + step * 10),
+            "Home": () => update(min),
+            "End": () => update(max),
+        });
+        onKeyDown?.(evt);
+    };
+
+    return (
+        <Element
+            tag="div"
+            role="slider"
+            tabIndex={0}
+            aria-valuemin={min}
+            aria-valuemax={max}
+            aria-valuenow={current}
+            onKeyDown={handleKeyDown}
+            onPointerDown={handlePointerDown}
+            onPointerMove={handlePointerMove}
+            {...rest as any}
+        >
+            <Div sl="relative flex-row-center height-16 cursor-pointer select-none">
+                <Div sl="grow-1 height-4 bg-#ccc border-radius-2" />
+                <Div
+                    sl="absolute width-12 height-12 bg-#333 border-radius-6"
+                    style={{ left: `calc(${percent}% - 6px)` }}
+                />
+            </Div>
+        </Element>
+    );
+}
+
+function clampValue(value: number, min: number, max: number, step: number): number {
+    if (step > 0) {
+        value = min + Math.round((value - min) / step) * step;
+    }
+    return Math.min(max, Math.max(min, value));
+}
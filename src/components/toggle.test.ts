@@ -0,0 +1,19 @@
+import { expect } from "https://deno.land/std@0.208.0/expect/mod.ts";
+import { describe, it } from "https://deno.land/std@0.208.0/testing/bdd.ts";
+import { Toggle } from "./toggle.tsx";
+
+describe("Toggle", () => {
+    it("should emit the flipped state when clicked", () => {
+        const values: boolean[] = [];
+        const onChange = (checked: boolean) => values.push(checked);
+
+        Toggle({ checked: false, onChange }).props.onClick({});
+        Toggle({ checked: true, onChange }).props.onClick({});
+        expect(values).toEqual([true, false]);
+    });
+
+    it("should reflect the checked state", () => {
+        expect(Toggle({ checked: false }).props["aria-checked"]).toBe(false);
+        expect(Toggle({ checked: true }).props["aria-checked"]).toBe(true);
+    });
+});
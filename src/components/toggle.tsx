@@ -0,0 +1,54 @@
+import React, { JSX } from "react";
+import { Element, TagProps } from "./element.tsx";
+import { Div } from "./div.tsx";
+
+type ToggleProps =
+    & Omit<TagProps<"button">, "tag" | "onChange">
+    & {
+        checked: boolean;
+        onChange?: (checked: boolean) => void;
+    };
+
+/**
+ * Controlled on/off switch. Clicking it (or pressing Space/Enter while it is
+ * focused, as with any button) calls `onChange` with the flipped value.
+ */
+export function Toggle(
+    {
+        checked,
+        onChange,
+        onClick,
+        ...rest
+    }: ToggleProps,
+): JSX.Element {
+    const handleClick = (evt: React.MouseEvent<HTMLButtonElement>) => {
+        onChange?.(!checked);
+        onClick?.(evt);
+    };
+
+    return (
+        <Element
+            tag="button"
+            type="button"
+            role="switch"
+            aria-checked={checked}
+            onClick={handleClick}
+            sl="p0 border-none bg-transparent cursor-pointer"
+            {...rest as any}
+        >
+            <Div
+                sl={[
+                    "relative width-32 height-16 border-radius-8",
+                    checked ? "bg-#4a8" : "bg-#ccc",
+                ]}
+            >
+                <Div
+                    sl={[
+                        "absolute top-2 width-12 height-12 bg-white border-radius-6",
+                        checked ? "left-18" : "left-2",
+                    ]}
+                />
+            </Div>
+        </Element>
+    );
+}
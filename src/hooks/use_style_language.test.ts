@@ -0,0 +1,157 @@
+import { expect } from "https://deno.land/std@0.208.0/expect/mod.ts";
+import { describe, it } from "https://deno.land/std@0.208.0/testing/bdd.ts";
+import {
+    compileStyleLanguage,
+    defineStyleClass,
+    preprocessStyleLanguage,
+    removeStyleClass,
+    setStyleLanguageStrict,
+} from "./use_style_language.tsx";
+
+function compile(sl: string): string {
+    return compileStyleLanguage("test", preprocessStyleLanguage(sl));
+}
+
+function captureWarnings(fn: () => void): string[] {
+    const warnings: string[] = [];
+    const original = console.warn;
+    console.warn = (msg: string) => warnings.push(msg);
+    try {
+        fn();
+    } finally {
+        console.warn = original;
+    }
+    return warnings;
+}
+
+function withStyleClass(name: string, sl: string, fn: () => void): void {
+    defineStyleClass(name, sl);
+    try {
+        fn();
+    } finally {
+        removeStyleClass(name);
+    }
+}
+
+describe("style language", () => {
+    it("should map rounded-* tokens to border-radius", () => {
+        expect(compile("rounded-8")).toContain("border-radius: 8px;");
+        expect(compile("rounded-50%")).toContain("border-radius: 50%;");
+        expect(compile("rounded-full")).toContain("border-radius: 9999px;");
+    });
+
+    it("should map z-* tokens to z-index", () => {
+        expect(compile("z10")).toContain("z-index: 10;");
+        expect(compile("z-10")).toContain("z-index: 10;");
+        expect(compile("-z-10")).toContain("z-index: -10;");
+        expect(compile("z-auto")).toContain("z-index: auto;");
+    });
+
+    it("should wrap breakpoint-prefixed tokens in ordered media queries", () => {
+        const expected = [
+            ".test {",
+            "@media (min-width: 640px) {",
+            "width: 32px;",
+            "}",
+            "@media (min-width: 1024px) {",
+            "width: 64px;",
+            "}",
+            "}",
+        ].join("\n");
+        expect(compile("sm:width-32 lg:width-64")).toBe(expected);
+        expect(compile("lg:width-64 sm:width-32")).toBe(expected);
+    });
+
+    it("should emit base rules before breakpoint rules", () => {
+        const css = compile("lg:width-64 width-32");
+        expect(css.indexOf("width: 32px;")).toBeLessThan(css.indexOf("@media"));
+    });
+
+    it("should not treat inherited object keys as breakpoints", () => {
+        let css = "";
+        const warnings = captureWarnings(() => {
+            css = compile("constructor:width-32");
+        });
+        expect(css).not.toContain("@media");
+        expect(warnings.length).toBe(1);
+    });
+
+    it("should warn on unknown tokens and still apply valid ones", () => {
+        let css = "";
+        const warnings = captureWarnings(() => {
+            css = compile("widht-64 px8");
+        });
+        expect(warnings.length).toBe(1);
+        expect(warnings[0]).toContain("widht-64");
+        expect(css).toContain("padding-left: 8px; padding-right: 8px;");
+    });
+
+    it("should throw on unknown tokens in strict mode", () => {
+        setStyleLanguageStrict(true);
+        try {
+            expect(() => compile("widht-64")).toThrow("widht-64");
+            expect(compile("px8")).toContain("padding-left: 8px;");
+        } finally {
+            setStyleLanguageStrict(false);
+        }
+    });
+
+    it("should map transition tokens to a transition over the duration", () => {
+        expect(compile("transition-bg-200")).toContain("transition: background-color 200ms;");
+        expect(compile("transition-150")).toContain("transition: all 150ms;");
+    });
+
+    it("should combine multiple transition tokens into one declaration", () => {
+        const css = compile("transition-bg-200 px8 transition-outline-300");
+        expect(css).toContain("transition: background-color 200ms, outline-color 300ms;");
+        expect(css.match(/transition:/g)?.length).toBe(1);
+    });
+
+    it("should expand registered style classes", () => {
+        withStyleClass("x", "p8 rounded-4", () => {
+            expect(preprocessStyleLanguage("@x mt16")).toEqual(["p8", "rounded-4", "mt16"]);
+
+            const css = compile("@x mt16");
+            expect(css).toContain("padding: 8px;");
+            expect(css).toContain("border-radius: 4px;");
+            expect(css).toContain("margin-top: 16px;");
+        });
+    });
+
+    it("should apply a breakpoint prefix to each token of a style class", () => {
+        withStyleClass("x", "p8 rounded-4", () => {
+            expect(preprocessStyleLanguage("lg:@x")).toEqual(["lg:p8", "lg:rounded-4"]);
+        });
+    });
+
+    it("should report nested breakpoints in a prefixed style class", () => {
+        withStyleClass("x", "p8 sm:rounded-4", () => {
+            let tokens: string[] = [];
+            const warnings = captureWarnings(() => {
+                tokens = preprocessStyleLanguage("lg:@x");
+            });
+            expect(tokens).toEqual(["lg:p8"]);
+            expect(warnings.length).toBe(1);
+            expect(warnings[0]).toContain("sm:rounded-4");
+
+            setStyleLanguageStrict(true);
+            try {
+                expect(() => preprocessStyleLanguage("lg:@x")).toThrow("sm:rounded-4");
+            } finally {
+                setStyleLanguageStrict(false);
+            }
+        });
+    });
+
+    it("should report undefined style classes as unknown tokens", () => {
+        const warnings = captureWarnings(() => compile("@undefined-class px8"));
+        expect(warnings.length).toBe(1);
+        expect(warnings[0]).toContain("@undefined-class");
+    });
+
+    it("should no longer expand a removed style class", () => {
+        defineStyleClass("x", "p8");
+        expect(removeStyleClass("x")).toBe(true);
+        expect(preprocessStyleLanguage("@x")).toEqual(["@x"]);
+    });
+});
@@ -12,6 +12,13 @@
  * px8      - adds padding-left and padding-right of 8px
  * my16     - adds margin-top and margin-bottom of 16px
  * mx-auto  - adds margin-left and margin-right of auto
+ *
+ * Tokens can be prefixed with a responsive breakpoint (sm, md, lg, xl, 2xl)
+ * to apply only when the viewport is at least that wide. As in Tailwind,
+ * prefixed tokens always override unprefixed ones and larger breakpoints
+ * override smaller ones, independent of the order the tokens are written in:
+ *
+ * width-32 lg:width-64  - 32px wide, or 64px on viewports >= 1024px
  */
 
 import React from "react";
@@ -85,6 +92,86 @@ export function useStyleLanguage(sl: StyleLanguage | undefined): string {
     return className;
 }
 
+const styleClasses = new Map<string, string[]>();
+
+/**
+ * Registers a named, reusable set of style tokens which can then be referenced
+ * in any style language descriptor as "@name".  For example:
+ *
+ * defineStyleClass("panel", "p8 bg-#222 border-#444 rounded-4");
+ * <Div sl="@panel mt16" />
+ *
+ * The tokens are expanded at registration time, so a class may refer to other
+ * classes that were defined before it. A class can also be used with a
+ * breakpoint prefix (e.g. "lg:@panel") as long as it does not itself contain
+ * breakpoint-prefixed tokens.
+ */
+export function defineStyleClass(name: string, sl: StyleLanguage): void {
+    styleClasses.set(name, preprocessStyleLanguage(sl));
+}
+
+/**
+ * Removes a style class registered with `defineStyleClass`. Returns true if the
+ * class existed.
+ */
+export function removeStyleClass(name: string): boolean {
+    return styleClasses.delete(name);
+}
+
+function expandStyleClass(token: string): string[] {
+    // A class may be referenced with a breakpoint prefix, e.g. "lg:@panel", in
+    // which case the prefix is applied to each of the expanded tokens.
+    const [breakpoint, name] = splitBreakpoint(token);
+    if (!name.startsWith("@")) {
+        return [token];
+    }
+
+    // Unknown classes are passed through so they are reported like any other
+    // unrecognized token
+    const tokens = styleClasses.get(name.slice(1));
+    if (!tokens) {
+        return [token];
+    }
+    if (breakpoint === undefined) {
+        return tokens;
+    }
+
+    const expanded: string[] = [];
+    for (const t of tokens) {
+        if (splitBreakpoint(t)[0] !== undefined) {
+            reportStyleLanguageError(
+                `Cannot apply breakpoint '${breakpoint}:' to style class '${name}' ` +
+                    `as it already contains the breakpoint token '${t}'`,
+            );
+            continue;
+        }
+        expanded.push(`${breakpoint}:${t}`);
+    }
+    return expanded;
+}
+
+let strictMode = false;
+
+/**
+ * Enables or disables "strict" mode for the style language. By default unknown
+ * tokens only log a warning; in strict mode they throw an error instead. This is
+ * useful in tests or development builds to make typos in style tokens fail loudly.
+ */
+export function setStyleLanguageStrict(strict: boolean): void {
+    strictMode = strict;
+}
+
+/**
+ * Reports a problem in a style language descriptor: a console warning by
+ * default, or a thrown error in strict mode.
+ */
+function reportStyleLanguageError(msg: string): void {
+    if (strictMode) {
+        throw new Error(msg);
+    }
+    console.warn(msg);
+}
+
 /**
  * Reduces the style specification into a flat array of the active style tokens.
  * A "style token" is a single string mapping to a style rule, such as "px8" or
@@ -93,13 +180,19 @@ export function useStyleLanguage(sl: StyleLanguage | undefined): string {
  * The input style specification can be provided as a single string,
  * an array of strings, or an object mapping strings (to conditionally
  * enable/disable sets of styles).  This is for convenience of the caller.
+ *
+ * Exported for testing only; this is not re-exported from the package index.
  */
-function preprocessStyleLanguage(sl: StyleLanguage | undefined): string[] {
+export function preprocessStyleLanguage(sl: StyleLanguage | undefined): string[] {
     if (!sl) {
         return [];
     }
     const splitString = (sl: string): string[] => {
-        return sl.split(" ").map((s) => s.trim()).filter((s) => s.length > 0);
+        return sl.split(" ")
+            .map((s) => s.trim())
+            .filter((s) => s.length > 0)
+            .map((s) => expandStyleClass(s))
+            .flat();
     };
     if (typeof sl === "string") {
         return splitString(sl);
@@ -116,11 +209,17 @@ function preprocessStyleLanguage(sl: StyleLanguage | undefined): string[] {
 /**
  * Transforms the "style tokens" (individual tokens that should match particular
  * rules) into a well-formed CSS string that can be injected into the HTML.
+ *
+ * Exported for testing only; this is not re-exported from the package index.
  */
-function compileStyleLanguage(className: string, tokens: string[]): string {
+export function compileStyleLanguage(className: string, tokens: string[]): string {
     const lines: string[] = [];
+    const responsiveLines = new Map<string, string[]>();
+
+    for (const fullToken of tokens) {
+        // Split off an optional responsive breakpoint prefix, e.g. "lg:width-64"
+        const [breakpoint, token] = splitBreakpoint(fullToken);
 
-    for (const token of tokens) {
         let found = false;
         for (const [matcher, fn] of rulesTable()) {
             // Check for a match (exact string match or regular expression match)
@@ -154,7 +253,13 @@ function compileStyleLanguage(className: string, tokens: string[]): string {
             // If there was a match, add it to the current class definition.
             // Note that we implicitly trust result is valid CSS!
             const css = Array.isArray(result) ? result.join("\n") : result;
-            lines.push(css);
+            if (breakpoint === undefined) {
+                lines.push(css);
+            } else {
+                const list = responsiveLines.get(breakpoint) ?? [];
+                list.push(css);
+                responsiveLines.set(breakpoint, list);
+            }
             found = true;
             break;
         }
@@ -162,13 +267,77 @@ function compileStyleLanguage(className: string, tokens: string[]): string {
         // Warn if no matching rule was found for the token -- it's easy to introduce typos
         // in the style definition during development.
         if (!found) {
-            console.warn(`Unknown style language token: '${token}' in: '${tokens.join(" ")}'`);
+            reportStyleLanguageError(
+                `Unknown style language token: '${fullToken}' in: '${tokens.join(" ")}'`,
+            );
+        }
+    }
+
+    // Combine the base transitions before appending the breakpoint rules
+    const output = mergeTransitions(lines);
+
+    // Breakpoint rules are emitted after all the base rules, in ascending order of
+    // min-width, so that they override regardless of the token order in the input.
+    for (const [breakpoint, minWidth] of Object.entries(BREAKPOINTS)) {
+        const list = responsiveLines.get(breakpoint);
+        if (list) {
+            output.push(`@media (min-width: ${minWidth}px) {`, ...mergeTransitions(list), "}");
         }
     }
 
     // Return a CSS class definition. Note that this could include internal selectors
     // like "&:hover", so do not assume this will be a simple list of properties.
-    return [`.${className} {`, ...lines, "}"].join("\n");
+    return [`.${className} {`, ...output, "}"].join("\n");
+}
+
+/**
+ * The "transition" property is a shorthand, so separate transition tokens would
+ * override each other. This combines all the transition declarations into a
+ * single comma-separated declaration at the position of the first one.
+ */
+function mergeTransitions(lines: string[]): string[] {
+    const values: string[] = [];
+    const output: string[] = [];
+    let index = -1;
+    for (const line of lines) {
+        const m = line.match(/^transition: (.+);$/);
+        if (!m) {
+            output.push(line);
+            continue;
+        }
+        if (index === -1) {
+            index = output.length;
+            output.push(line);
+        }
+        values.push(m[1]);
+    }
+    if (index !== -1) {
+        output[index] = `transition: ${values.join(", ")};`;
+    }
+    return output;
+}
+
+/**
+ * Minimum viewport widths (in px) for the responsive breakpoint prefixes.
+ * These match the Tailwind defaults so "md:px16" means what one would expect.
+ */
+const BREAKPOINTS: Record<string, number> = {
+    sm: 640,
+    md: 768,
+    lg: 1024,
+    xl: 1280,
+    "2xl": 1536,
+};
+
+function splitBreakpoint(token: string): [string | undefined, string] {
+    const index = token.indexOf(":");
+    if (index > 0) {
+        const prefix = token.slice(0, index);
+        if (Object.hasOwn(BREAKPOINTS, prefix)) {
+            return [prefix, token.slice(index + 1)];
+        }
+    }
+    return [undefined, token];
 }
 
 let compiledRulesTable: StyleLanguageRule[] | null = null;
@@ -211,7 +380,7 @@ const RULES_TABLE_SOURCE: StyleLanguageRule[] = [
     //
     // absolute, relative, fixed, sticky
     // top-*, right-*, bottom-*, left-*
-    // z-*
+    // z-*, -z-*, z-auto
     // border-box, content-box
     // display-none, display-block, display-inline, display-inline-block,
     // display-flex, display-grid
@@ -226,8 +395,13 @@ const RULES_TABLE_SOURCE: StyleLanguageRule[] = [
         (m) => `${m[1]}: ${m[2]}px;`,
     ],
     [
-        /z-?([0-9]+)/,
-        (m) => `z-index: ${m[1]};`,
+        // Negative values use a leading dash as in Tailwind, e.g. "-z-10"
+        /(-?)z-?([0-9]+)/,
+        (m) => `z-index: ${m[1]}${m[2]};`,
+    ],
+    [
+        "z-auto",
+        () => "z-index: auto;",
     ],
     [
         /(border|content)-box/,
@@ -509,6 +683,9 @@ const RULES_TABLE_SOURCE: StyleLanguageRule[] = [
 
     //-------------------------------------------------------------------------
     // Borders
+    //
+    // border-*
+    // rounded-*, rounded-full
     //-------------------------------------------------------------------------
 
     [
@@ -531,11 +708,41 @@ const RULES_TABLE_SOURCE: StyleLanguageRule[] = [
         /border-(width|radius)-([0-9]+)/,
         (m) => `border-${m[1]}: ${m[2]}px;`,
     ],
+    [
+        "rounded-full",
+        () => "border-radius: 9999px;",
+    ],
+    [
+        /rounded-([0-9]+)%/,
+        (m) => `border-radius: ${m[1]}%;`,
+    ],
+    [
+        /rounded-([0-9]+)/,
+        (m) => `border-radius: ${m[1]}px;`,
+    ],
     [
         "outline-none",
         () => "outline: none;",
     ],
 
+    //-------------------------------------------------------------------------
+    // Transitions
+    //
+    // transition-*, transition-bg-*, transition-fg-*, transition-border-*,
+    // transition-outline-*, transition-opacity-*
+    //
+    // Durations are in milliseconds. Multiple transition tokens are combined
+    // into a single "transition" declaration by the compiler.
+    //-------------------------------------------------------------------------
+    [
+        /transition-([0-9]+)/,
+        (m) => `transition: all ${m[1]}ms;`,
+    ],
+    [
+        /transition-(bg|fg|border|outline|opacity)-([0-9]+)/,
+        (m) => `transition: ${TRANSITION_PROPERTIES[m[1]]} ${m[2]}ms;`,
+    ],
+
     //-------------------------------------------------------------------------
     // User select
     //-------------------------------------------------------------------------
@@ -545,6 +752,14 @@ const RULES_TABLE_SOURCE: StyleLanguageRule[] = [
     ],
 ];
 
+const TRANSITION_PROPERTIES: Record<string, string> = {
+    bg: "background-color",
+    fg: "color",
+    border: "border-color",
+    outline: "outline-color",
+    opacity: "opacity",
+};
+
 function matchNamedColors(prefix: string): (s: string) => string[] | undefined {
     return (s: string): string[] | undefined => {
         if (!s.startsWith(prefix)) {
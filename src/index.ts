@@ -3,8 +3,10 @@ export * from "./components/button.tsx";
 export * from "./components/div.tsx";
 export * from "./components/element.tsx";
 export * from "./components/input.tsx";
+export * from "./components/slider.tsx";
 export * from "./components/span.tsx";
 export * from "./components/textarea.tsx";
+export * from "./components/toggle.tsx";
 
 export * from "./dom/invoke_download.ts";
 
@@ -17,7 +19,13 @@ export * from "./hooks/use_event_listener.ts";
 export * from "./hooks/use_global_key_mapping.ts";
 export * from "./hooks/use_local_storage.ts";
 export * from "./hooks/use_server_side_events.ts";
-export * from "./hooks/use_style_language.tsx";
+export {
+    defineStyleClass,
+    removeStyleClass,
+    setStyleLanguageStrict,
+    type StyleLanguage,
+    useStyleLanguage,
+} from "./hooks/use_style_language.tsx";
 export * from "./hooks/use_throttled_callback.ts";
 export * from "./hooks/use_url_change_listener.tsx";
 